@@ -1,8 +1,16 @@
-use std::{env, time::Duration};
+use std::{
+    collections::HashMap,
+    env,
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
+use lru::LruCache;
 use prover_service::JobResult::{Failure, ProofGenerated};
 use prover_service::{JobReporter, JobResult};
 use tokio::runtime::Handle;
+use tokio::sync::mpsc;
 use zkevm_test_harness::abstract_zksync_circuit::concrete_circuits::ZkSyncProof;
 use zkevm_test_harness::pairing::bn256::Bn256;
 
@@ -10,13 +18,161 @@ use zksync_config::ProverConfig;
 use zksync_dal::{connection::DbVariant, ConnectionPool};
 use zksync_object_store::{Bucket, ObjectStore, ObjectStoreFactory};
 
+/// Bound on the in-flight report queue. Applies backpressure to the proving threads if the drain
+/// task falls behind rather than growing memory without limit.
+const REPORT_CHANNEL_CAPACITY: usize = 1_024;
+
+/// Capacity of the `job_id -> circuit_type` memoization cache. The circuit type never changes for a
+/// job, so caching lets the drain task avoid the repeated `get_prover_job_by_id` lookups.
+const CIRCUIT_TYPE_CACHE_CAPACITY: usize = 10_000;
+
+/// Backoff parameters for [`ReportProcessor::next_retry_delay`]. These are crate-local constants
+/// rather than `ProverConfig` fields: there is no schema column yet to persist a per-job retry
+/// delay to, so the delay is only ever observed via the log line in `persist_failure`, not fed
+/// back into the DAL.
+const RETRY_BACKOFF_BASE: Duration = Duration::from_secs(5);
+const RETRY_BACKOFF_FACTOR: f64 = 2.0;
+const RETRY_BACKOFF_CAP: Duration = Duration::from_secs(300);
+
+/// Verbose per-stage lifecycle logging (synthesis, finalize, encode, decode, transfer, idle).
+/// Compiled out unless the `completion-logging` feature is enabled so a production prover image
+/// stays quiet; developers build with the feature for the full lifecycle trace. Metrics are emitted
+/// unconditionally regardless of this feature.
+macro_rules! completion_trace {
+    ($($arg:tt)*) => {{
+        #[cfg(feature = "completion-logging")]
+        vlog::trace!($($arg)*);
+    }};
+}
+
+/// Failure logging. Gated behind the `error-logging` feature, which is enabled by default so a lean
+/// image still reports failures; it can be compiled out only by an explicit `default-features =
+/// false` opt-out.
+macro_rules! error_log {
+    ($($arg:tt)*) => {{
+        #[cfg(feature = "error-logging")]
+        vlog::error!($($arg)*);
+    }};
+}
+
+/// Classified prover failure, replacing the opaque error string so the queue can distinguish a
+/// retryable infrastructure hiccup from a fatal circuit/witness error or an OOM kill.
+#[derive(Debug, thiserror::Error)]
+pub enum ProverJobError {
+    #[error("failed to load setup: {0}")]
+    SetupLoad(String),
+    #[error("failed to decode assembly: {0}")]
+    AssemblyDecode(String),
+    #[error("circuit synthesis failed: {0}")]
+    Synthesis(String),
+    #[error("proof generation failed: {0}")]
+    ProofGeneration(String),
+    #[error("object store error: {0}")]
+    ObjectStore(String),
+    #[error("prover ran out of memory")]
+    OutOfMemory,
+}
+
+impl ProverJobError {
+    /// Whether the job should consume an attempt and be retried (`true`) or be treated as
+    /// immediately terminal (`false`).
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::SetupLoad(_) | Self::ObjectStore(_) | Self::OutOfMemory
+        )
+    }
+
+    /// Stable discriminant persisted alongside the message so operators can group on failure kind.
+    pub fn discriminant(&self) -> &'static str {
+        match self {
+            Self::SetupLoad(_) => "setup_load",
+            Self::AssemblyDecode(_) => "assembly_decode",
+            Self::Synthesis(_) => "synthesis",
+            Self::ProofGeneration(_) => "proof_generation",
+            Self::ObjectStore(_) => "object_store",
+            Self::OutOfMemory => "out_of_memory",
+        }
+    }
+
+    /// Classifies a free-form error reported by the proving backend. OOM, object-store, setup and
+    /// synthesis failures are recognized by their markers; everything else is treated as a fatal
+    /// proof generation error so a deterministic bug is not retried forever.
+    pub fn classify(error: String) -> Self {
+        let lower = error.to_lowercase();
+        if lower.contains("out of memory") || lower.contains("oom") {
+            Self::OutOfMemory
+        } else if lower.contains("object store") || lower.contains("gcs") {
+            Self::ObjectStore(error)
+        } else if lower.contains("setup") {
+            Self::SetupLoad(error)
+        } else if lower.contains("synthesis") {
+            Self::Synthesis(error)
+        } else {
+            Self::ProofGeneration(error)
+        }
+    }
+}
+
+/// Rolling statistics for a single duration series (e.g. proof generation time).
+#[derive(Debug, Clone, Default)]
+pub struct DurationStats {
+    pub count: u64,
+    pub total: Duration,
+    pub min: Option<Duration>,
+    pub max: Option<Duration>,
+}
+
+impl DurationStats {
+    fn observe(&mut self, duration: Duration) {
+        self.count += 1;
+        self.total += duration;
+        self.min = Some(self.min.map_or(duration, |m| m.min(duration)));
+        self.max = Some(self.max.map_or(duration, |m| m.max(duration)));
+    }
+
+    /// Rolling average over the observed samples, or `None` if there are none.
+    pub fn average(&self) -> Option<Duration> {
+        (self.count > 0).then(|| self.total / self.count as u32)
+    }
+}
+
+/// Aggregate outcome counts and duration series for a single circuit type.
+#[derive(Debug, Clone, Default)]
+pub struct CircuitStats {
+    pub proofs_generated: u64,
+    pub failures: u64,
+    pub synthesized: u64,
+    pub assembly_finalized: u64,
+    pub proof_generation_time: DurationStats,
+}
+
+/// Cheap in-process snapshot of aggregate prover health, keyed by circuit type.
+///
+/// Updated on every [`JobResult`] the reporter handles so an operator API can answer "how many
+/// proofs of circuit X completed in the last window and at what average generation time" without
+/// scraping Prometheus.
+#[derive(Debug, Clone, Default)]
+pub struct Stats {
+    pub per_circuit: HashMap<String, CircuitStats>,
+}
+
+impl Stats {
+    fn circuit(&mut self, circuit_type: &str) -> &mut CircuitStats {
+        self.per_circuit.entry(circuit_type.to_string()).or_default()
+    }
+}
+
+/// Reporter handed to `prover_service`. It does no DB or object-store work itself: every report is
+/// enqueued onto a bounded channel and drained asynchronously by a [`ReportProcessor`] task, so a
+/// DB round trip no longer serializes on the proof-completion hot path. It blocks only while the
+/// channel is full, until the drain task frees a slot.
 #[derive(Debug)]
 pub struct ProverReporter {
     rt_handle: Handle,
     pool: ConnectionPool,
-    config: ProverConfig,
-    processed_by: String,
-    object_store: Box<dyn ObjectStore>,
+    sender: mpsc::Sender<JobResult>,
+    stats: Arc<Mutex<Stats>>,
 }
 
 fn assembly_debug_blob_url(job_id: usize, circuit_id: u8) -> String {
@@ -30,95 +186,102 @@ impl ProverReporter {
         rt_handle: Handle,
     ) -> Self {
         let pool = rt_handle.block_on(ConnectionPool::new(Some(1), DbVariant::Prover));
-        Self {
-            pool,
+        let stats = Arc::new(Mutex::new(Stats::default()));
+        let (sender, receiver) = mpsc::channel(REPORT_CHANNEL_CAPACITY);
+
+        let processor = ReportProcessor {
+            pool: pool.clone(),
             config,
-            processed_by: env::var("POD_NAME").unwrap_or("Unknown".to_string()),
-            object_store: rt_handle.block_on(store_factory.create_store()),
+            processed_by: env::var("POD_NAME").unwrap_or_else(|_| "Unknown".to_string()),
+            object_store: Arc::from(rt_handle.block_on(store_factory.create_store())),
+            stats: stats.clone(),
+            circuit_types: LruCache::new(
+                NonZeroUsize::new(CIRCUIT_TYPE_CACHE_CAPACITY).unwrap(),
+            ),
+        };
+        rt_handle.spawn(processor.run(receiver));
+
+        Self {
             rt_handle,
+            pool,
+            sender,
+            stats,
         }
     }
 
-    fn handle_successful_proof_generation(
-        &self,
-        job_id: usize,
-        proof: ZkSyncProof<Bn256>,
-        duration: Duration,
-        index: usize,
-    ) {
-        let circuit_type = self.get_circuit_type(job_id);
-        let serialized = bincode::serialize(&proof).expect("Failed to serialize proof");
-        vlog::info!(
-            "Successfully generated proof with id {:?} and type: {} for index: {}. Size: {:?}KB took: {:?}",
-            job_id,
-            circuit_type,
-            index,
-            serialized.len() >> 10,
-            duration,
-        );
-        metrics::histogram!(
-            "server.prover.proof_generation_time",
-            duration,
-            "circuit_type" => circuit_type,
-        );
-        let job_id = job_id as u32;
-        self.rt_handle.block_on(async {
-            let mut connection = self.pool.access_storage().await;
-            let mut transaction = connection.start_transaction().await;
-
-            transaction
-                .prover_dal()
-                .save_proof(job_id, duration, serialized, &self.processed_by)
-                .await;
-            let _prover_job_metadata = transaction
-                .prover_dal()
-                .get_prover_job_by_id(job_id)
-                .await
-                .unwrap_or_else(|| panic!("No job with id: {} exist", job_id));
-
-            transaction.commit().await;
-        });
+    /// Returns a point-in-time snapshot of the aggregate statistics.
+    pub fn get_stats(&self) -> Stats {
+        self.stats.lock().unwrap().clone()
     }
 
-    fn get_circuit_type(&self, job_id: usize) -> String {
-        let prover_job_metadata = self.rt_handle.block_on(async {
-            self.pool
-                .access_storage()
-                .await
-                .prover_dal()
-                .get_prover_job_by_id(job_id as u32)
-                .await
-                .unwrap_or_else(|| panic!("No job with id: {} exist", job_id))
-        });
-        prover_job_metadata.circuit_type
+    /// Clears the accumulated statistics, e.g. at the start of a new reporting window.
+    pub fn reset(&self) {
+        *self.stats.lock().unwrap() = Stats::default();
     }
+
 }
 
 impl JobReporter for ProverReporter {
     fn send_report(&mut self, report: JobResult) {
+        // Enqueue without blocking the proving thread. If the drain task is momentarily behind,
+        // fall back to an awaited send (bounded backpressure) rather than dropping the report.
+        match self.sender.try_send(report) {
+            Ok(()) => {}
+            Err(mpsc::error::TrySendError::Full(report)) => {
+                if self
+                    .rt_handle
+                    .block_on(self.sender.send(report))
+                    .is_err()
+                {
+                    vlog::error!("prover report channel closed; dropping report");
+                }
+            }
+            Err(mpsc::error::TrySendError::Closed(_)) => {
+                vlog::error!("prover report channel closed; dropping report");
+            }
+        }
+    }
+}
+
+/// Async task that drains the report channel, caching `circuit_type` lookups and keeping all DB and
+/// object-store I/O off the proving threads.
+struct ReportProcessor {
+    pool: ConnectionPool,
+    config: ProverConfig,
+    processed_by: String,
+    object_store: Arc<dyn ObjectStore>,
+    stats: Arc<Mutex<Stats>>,
+    circuit_types: LruCache<u32, String>,
+}
+
+impl ReportProcessor {
+    async fn run(mut self, mut receiver: mpsc::Receiver<JobResult>) {
+        while let Some(report) = receiver.recv().await {
+            self.handle(report).await;
+        }
+    }
+
+    async fn handle(&mut self, report: JobResult) {
         match report {
             Failure(job_id, error) => {
-                vlog::error!(
+                error_log!(
                     "Failed to generate proof for id {:?}. error reason; {}",
                     job_id,
                     error
                 );
-                self.rt_handle.block_on(async {
-                    self.pool
-                        .access_storage()
-                        .await
-                        .prover_dal()
-                        .save_proof_error(job_id as u32, error, self.config.max_attempts)
-                        .await;
-                });
+                let circuit_type = self.get_circuit_type(job_id).await;
+                self.stats.lock().unwrap().circuit(&circuit_type).failures += 1;
+                self.persist_failure(job_id, ProverJobError::classify(error))
+                    .await;
             }
             ProofGenerated(job_id, duration, proof, index) => {
-                self.handle_successful_proof_generation(job_id, proof, duration, index);
+                self.handle_successful_proof_generation(job_id, proof, duration, index)
+                    .await;
             }
 
             JobResult::Synthesized(job_id, duration) => {
-                let circuit_type = self.get_circuit_type(job_id);
-                vlog::trace!(
+                let circuit_type = self.get_circuit_type(job_id).await;
+                completion_trace!(
                     "Successfully synthesized circuit with id {:?} and type: {}. took: {:?}",
                     job_id,
                     circuit_type,
@@ -127,12 +290,13 @@ impl JobReporter for ProverReporter {
                 metrics::histogram!(
                     "server.prover.circuit_synthesis_time",
                     duration,
-                    "circuit_type" => circuit_type,
+                    "circuit_type" => circuit_type.clone(),
                 );
+                self.stats.lock().unwrap().circuit(&circuit_type).synthesized += 1;
             }
             JobResult::AssemblyFinalized(job_id, duration) => {
-                let circuit_type = self.get_circuit_type(job_id);
-                vlog::trace!(
+                let circuit_type = self.get_circuit_type(job_id).await;
+                completion_trace!(
                     "Successfully finalized assembly with id {:?} and type: {}. took: {:?}",
                     job_id,
                     circuit_type,
@@ -141,13 +305,18 @@ impl JobReporter for ProverReporter {
                 metrics::histogram!(
                     "server.prover.assembly_finalize_time",
                     duration,
-                    "circuit_type" => circuit_type,
+                    "circuit_type" => circuit_type.clone(),
                 );
+                self.stats
+                    .lock()
+                    .unwrap()
+                    .circuit(&circuit_type)
+                    .assembly_finalized += 1;
             }
 
             JobResult::SetupLoaded(job_id, duration, cache_miss) => {
-                let circuit_type = self.get_circuit_type(job_id);
-                vlog::trace!(
+                let circuit_type = self.get_circuit_type(job_id).await;
+                completion_trace!(
                     "Successfully setup loaded with id {:?} and type: {}. \
                      took: {:?} and had cache_miss: {}",
                     job_id,
@@ -167,8 +336,8 @@ impl JobReporter for ProverReporter {
                 );
             }
             JobResult::AssemblyEncoded(job_id, duration) => {
-                let circuit_type = self.get_circuit_type(job_id);
-                vlog::trace!(
+                let circuit_type = self.get_circuit_type(job_id).await;
+                completion_trace!(
                     "Successfully encoded assembly with id {:?} and type: {}. took: {:?}",
                     job_id,
                     circuit_type,
@@ -181,8 +350,8 @@ impl JobReporter for ProverReporter {
                 );
             }
             JobResult::AssemblyDecoded(job_id, duration) => {
-                let circuit_type = self.get_circuit_type(job_id);
-                vlog::trace!(
+                let circuit_type = self.get_circuit_type(job_id).await;
+                completion_trace!(
                     "Successfully decoded assembly with id {:?} and type: {}. took: {:?}",
                     job_id,
                     circuit_type,
@@ -195,23 +364,31 @@ impl JobReporter for ProverReporter {
                 );
             }
             JobResult::FailureWithDebugging(job_id, circuit_id, assembly, error) => {
-                vlog::trace!(
+                error_log!(
                     "Failed assembly decoding for job-id {} and circuit-type: {}. error: {}",
                     job_id,
                     circuit_id,
                     error,
                 );
+                let circuit_type = self.get_circuit_type(job_id).await;
+                self.stats.lock().unwrap().circuit(&circuit_type).failures += 1;
+                // Upload the debug assembly concurrently so it doesn't block report processing.
+                let object_store = self.object_store.clone();
                 let blob_url = assembly_debug_blob_url(job_id, circuit_id);
-                let put_task = self
-                    .object_store
-                    .put_raw(Bucket::ProverJobs, &blob_url, assembly);
-                self.rt_handle
-                    .block_on(put_task)
-                    .expect("Failed saving debug assembly to GCS");
+                tokio::spawn(async move {
+                    if let Err(err) = object_store
+                        .put_raw(Bucket::ProverJobs, &blob_url, assembly)
+                        .await
+                    {
+                        vlog::error!("Failed saving debug assembly to object store: {}", err);
+                    }
+                });
+                self.persist_failure(job_id, ProverJobError::AssemblyDecode(error))
+                    .await;
             }
             JobResult::AssemblyTransferred(job_id, duration) => {
-                let circuit_type = self.get_circuit_type(job_id);
-                vlog::trace!(
+                let circuit_type = self.get_circuit_type(job_id).await;
+                completion_trace!(
                     "Successfully transferred assembly with id {:?} and type: {}. took: {:?}",
                     job_id,
                     circuit_type,
@@ -224,7 +401,7 @@ impl JobReporter for ProverReporter {
                 );
             }
             JobResult::ProverWaitedIdle(prover_id, duration) => {
-                vlog::trace!(
+                completion_trace!(
                     "Prover wait idle time: {:?} for prover-id: {:?}",
                     duration,
                     prover_id
@@ -232,13 +409,124 @@ impl JobReporter for ProverReporter {
                 metrics::histogram!("server.prover.prover_wait_idle_time", duration,);
             }
             JobResult::SetupLoaderWaitedIdle(duration) => {
-                vlog::trace!("Setup load wait idle time: {:?}", duration);
+                completion_trace!("Setup load wait idle time: {:?}", duration);
                 metrics::histogram!("server.prover.setup_load_wait_wait_idle_time", duration,);
             }
             JobResult::SchedulerWaitedIdle(duration) => {
-                vlog::trace!("Scheduler wait idle time: {:?}", duration);
+                completion_trace!("Scheduler wait idle time: {:?}", duration);
                 metrics::histogram!("server.prover.scheduler_wait_idle_time", duration,);
             }
         }
     }
+
+    async fn handle_successful_proof_generation(
+        &mut self,
+        job_id: usize,
+        proof: ZkSyncProof<Bn256>,
+        duration: Duration,
+        index: usize,
+    ) {
+        let circuit_type = self.get_circuit_type(job_id).await;
+        let serialized = bincode::serialize(&proof).expect("Failed to serialize proof");
+        vlog::info!(
+            "Successfully generated proof with id {:?} and type: {} for index: {}. Size: {:?}KB took: {:?}",
+            job_id,
+            circuit_type,
+            index,
+            serialized.len() >> 10,
+            duration,
+        );
+        metrics::histogram!(
+            "server.prover.proof_generation_time",
+            duration,
+            "circuit_type" => circuit_type.clone(),
+        );
+        {
+            let mut stats = self.stats.lock().unwrap();
+            let circuit = stats.circuit(&circuit_type);
+            circuit.proofs_generated += 1;
+            circuit.proof_generation_time.observe(duration);
+        }
+        // Single write: the circuit type is already cached, so the redundant follow-up
+        // `get_prover_job_by_id` query is gone.
+        self.pool
+            .access_storage()
+            .await
+            .prover_dal()
+            .save_proof(job_id as u32, duration, serialized, &self.processed_by)
+            .await;
+    }
+
+    /// Computes the delay before a failed job becomes eligible for re-dispatch, following an
+    /// exponential backoff `min(cap, base * factor^attempt)` with ±10% jitter to avoid a
+    /// thundering herd after a shared outage.
+    ///
+    /// The jitter is seeded by both `job_id` and `attempt`: a shared outage is precisely the case
+    /// where many jobs fail at the same `attempt` count simultaneously, so jitter derived from
+    /// `attempt` alone would schedule all of them for the identical instant and reproduce the
+    /// thundering herd this is meant to prevent.
+    fn next_retry_delay(&self, job_id: usize, attempt: u32) -> Duration {
+        let base = RETRY_BACKOFF_BASE.as_secs_f64();
+        let factor = RETRY_BACKOFF_FACTOR.powi(attempt as i32);
+        let cap = RETRY_BACKOFF_CAP.as_secs_f64();
+        let delay = (base * factor).min(cap);
+        let seed = job_id as f64 * 12.989_8 + attempt as f64 * 2.399_963;
+        let jitter = 1.0 + 0.1 * ((seed.sin()).abs() * 2.0 - 1.0);
+        Duration::from_secs_f64((delay * jitter).max(0.0))
+    }
+
+    /// Persists a classified failure. Retryable errors (setup, object store, OOM) log the computed
+    /// backoff delay so it is observable even though there is no DAL column yet to persist it to.
+    /// Fatal errors (assembly decoding, synthesis, proof generation) skip that log but are saved
+    /// with the same `save_proof_error` call and `max_attempts`; they are not marked permanently
+    /// failed, so they are re-dispatched like retryable ones. The discriminant is folded into the
+    /// persisted message since `save_proof_error` has no dedicated column for it either.
+    async fn persist_failure(&self, job_id: usize, job_error: ProverJobError) {
+        let retryable = job_error.is_retryable();
+        let discriminant = job_error.discriminant();
+        let message = job_error.to_string();
+        let mut connection = self.pool.access_storage().await;
+        if retryable {
+            let attempts = connection
+                .prover_dal()
+                .get_prover_job_by_id(job_id as u32)
+                .await
+                .map(|metadata| metadata.attempts)
+                .unwrap_or(0);
+            let delay = self.next_retry_delay(job_id, attempts);
+            vlog::info!(
+                "job {} failed with retryable error ({}); eligible for re-dispatch in {:?}",
+                job_id,
+                discriminant,
+                delay
+            );
+        }
+        connection
+            .prover_dal()
+            .save_proof_error(
+                job_id as u32,
+                format!("[{}] {}", discriminant, message),
+                self.config.max_attempts,
+            )
+            .await;
+    }
+
+    /// Returns the circuit type for a job, memoizing the lookup since it never changes for a job.
+    async fn get_circuit_type(&mut self, job_id: usize) -> String {
+        let key = job_id as u32;
+        if let Some(circuit_type) = self.circuit_types.get(&key) {
+            return circuit_type.clone();
+        }
+        let circuit_type = self
+            .pool
+            .access_storage()
+            .await
+            .prover_dal()
+            .get_prover_job_by_id(key)
+            .await
+            .unwrap_or_else(|| panic!("No job with id: {} exist", job_id))
+            .circuit_type;
+        self.circuit_types.put(key, circuit_type.clone());
+        circuit_type
+    }
 }
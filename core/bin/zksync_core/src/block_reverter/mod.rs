@@ -2,7 +2,11 @@ use bitflags::bitflags;
 use serde::Serialize;
 use tokio::time::sleep;
 
-use std::path::Path;
+pub mod reorg_detector;
+
+use std::cmp;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use zksync_config::{ContractsConfig, DBConfig, ETHSenderConfig};
@@ -10,19 +14,47 @@ use zksync_contracts::zksync_contract;
 use zksync_dal::ConnectionPool;
 use zksync_merkle_tree::domain::ZkSyncTree;
 use zksync_state::RocksdbStorage;
+use rocksdb::checkpoint::Checkpoint;
+use rocksdb::{Options as RocksDbOptions, DB};
 use zksync_storage::RocksDB;
 use zksync_types::aggregated_operations::AggregatedActionType;
 use zksync_types::ethabi::Token;
 use zksync_types::web3::{
     contract::{Contract, Options},
     transports::Http,
-    types::{BlockId, BlockNumber},
+    types::{BlockId, BlockNumber, CallRequest},
     Web3,
 };
 use zksync_types::{L1BatchNumber, PackedEthSignature, H160, H256, U256};
 
 use zksync_eth_signer::{EthereumSigner, PrivateKeySigner, TransactionParameters};
 
+/// Errors that can occur while reverting node state.
+///
+/// Every variant is recoverable: the reverter is designed to be embeddable (e.g. in the
+/// reorg-detection driver or a supervisor that retries), so a failure surfaces as a `Result`
+/// instead of aborting the whole process.
+#[derive(Debug, thiserror::Error)]
+pub enum BlockReverterError {
+    #[error("database access error: {0}")]
+    Database(String),
+    #[error("L1 client error: {0}")]
+    L1Client(String),
+    #[error(
+        "tree root hash mismatch for L1 batch {l1_batch}: tree has {tree_root:?}, \
+         Postgres has {storage_root:?}"
+    )]
+    RootHashMismatch {
+        l1_batch: L1BatchNumber,
+        tree_root: H256,
+        storage_root: H256,
+    },
+    #[error("attempt to revert batches already executed on L1 (last executed: {0})")]
+    RevertExecutedBatch(L1BatchNumber),
+    #[error("failed to sign or broadcast revert transaction: {0}")]
+    Signing(String),
+}
+
 bitflags! {
     pub struct BlockReverterFlags: u32 {
         const POSTGRES = 0b_0001;
@@ -51,6 +83,33 @@ pub struct BlockReverterEthConfig {
     diamond_proxy_addr: H160,
     validator_timelock_addr: H160,
     default_priority_fee_per_gas: u64,
+    resubmit_config: ResubmitConfig,
+}
+
+/// Controls how a stuck revert transaction is re-broadcast with an escalated fee.
+///
+/// The revert tx keeps the same nonce across resubmissions so that each broadcast is a
+/// replacement (RBF) of the previous pending one rather than a new transaction.
+#[derive(Debug, Clone)]
+pub struct ResubmitConfig {
+    /// Number of L1 blocks to wait for inclusion before escalating the fee and rebroadcasting.
+    pub inclusion_timeout_blocks: u64,
+    /// Upper bound on `max_fee_per_gas`. Once escalation would exceed this value the reverter
+    /// gives up rather than looping forever.
+    pub max_fee_per_gas_ceiling: U256,
+    /// Multiplier applied to the `eth_estimateGas` result as a safety margin (e.g. `1.2`).
+    pub gas_limit_multiplier: f64,
+}
+
+impl Default for ResubmitConfig {
+    fn default() -> Self {
+        Self {
+            inclusion_timeout_blocks: 6,
+            // 500 gwei; high enough to clear any realistic spike, low enough to stay a guard rail.
+            max_fee_per_gas_ceiling: U256::from(500u64) * U256::exp10(9),
+            gas_limit_multiplier: 1.2,
+        }
+    }
 }
 
 impl BlockReverterEthConfig {
@@ -69,8 +128,15 @@ impl BlockReverterEthConfig {
             diamond_proxy_addr: contract.diamond_proxy_addr,
             validator_timelock_addr: contract.validator_timelock_addr,
             default_priority_fee_per_gas: eth_config.gas_adjuster.default_priority_fee_per_gas,
+            resubmit_config: ResubmitConfig::default(),
         }
     }
+
+    /// Overrides the fee-escalation policy used when a revert tx is not included in time.
+    pub fn with_resubmit_config(mut self, resubmit_config: ResubmitConfig) -> Self {
+        self.resubmit_config = resubmit_config;
+        self
+    }
 }
 
 /// This struct is used to perform a rollback of the state.
@@ -86,12 +152,21 @@ impl BlockReverterEthConfig {
 /// - State of the merkle tree
 /// - State of the state_keeper cache
 /// - State of the Ethereum contract (if the block was committed)
+/// Default number of L1 batches deleted per Postgres transaction during a rollback. Chosen so that
+/// even a deep rollback commits frequently enough to avoid holding locks / bloating WAL for minutes.
+const DEFAULT_ROLLBACK_CHUNK_SIZE: u32 = 1;
+
 #[derive(Debug)]
 pub struct BlockReverter {
     db_config: DBConfig,
     eth_config: Option<BlockReverterEthConfig>,
     connection_pool: ConnectionPool,
     executed_batches_revert_mode: L1ExecutedBatchesRevert,
+    /// Number of L1 batches removed per committed Postgres transaction during `rollback_postgres`.
+    rollback_chunk_size: u32,
+    /// When set, a point-in-time copy of the RocksDB stores is written here before they are
+    /// mutated, giving operators an abort path for a misjudged manual revert.
+    backup_path: Option<PathBuf>,
 }
 
 impl BlockReverter {
@@ -106,15 +181,37 @@ impl BlockReverter {
             db_config,
             connection_pool,
             executed_batches_revert_mode,
+            rollback_chunk_size: DEFAULT_ROLLBACK_CHUNK_SIZE,
+            backup_path: None,
         }
     }
 
+    /// Sets how many L1 batches are deleted per committed Postgres transaction during a rollback.
+    /// A larger chunk is faster but holds locks longer; `1` minimizes lock time.
+    pub fn with_rollback_chunk_size(mut self, rollback_chunk_size: u32) -> Self {
+        assert!(rollback_chunk_size > 0, "rollback chunk size must be positive");
+        self.rollback_chunk_size = rollback_chunk_size;
+        self
+    }
+
+    /// Enables pre-rollback RocksDB snapshots, writing them under `backup_path`. Each snapshot is
+    /// tagged with the source L1 batch number and can be restored with [`Self::restore_rocks_dbs`].
+    pub fn with_backup_path(mut self, backup_path: impl Into<PathBuf>) -> Self {
+        self.backup_path = Some(backup_path.into());
+        self
+    }
+
+    /// Returns the mode this reverter enforces for batches already executed on L1.
+    pub fn executed_batches_revert_mode(&self) -> &L1ExecutedBatchesRevert {
+        &self.executed_batches_revert_mode
+    }
+
     /// Rolls back DBs (Postgres + RocksDB) to a previous state.
     pub async fn rollback_db(
         &self,
         last_l1_batch_to_keep: L1BatchNumber,
         flags: BlockReverterFlags,
-    ) {
+    ) -> Result<(), BlockReverterError> {
         let rollback_tree = flags.contains(BlockReverterFlags::TREE);
         let rollback_postgres = flags.contains(BlockReverterFlags::POSTGRES);
         let rollback_sk_cache = flags.contains(BlockReverterFlags::SK_CACHE);
@@ -128,19 +225,23 @@ impl BlockReverter {
                 .blocks_dal()
                 .get_number_of_last_block_executed_on_eth()
                 .await
-                .expect("failed to get last executed L1 block");
-            assert!(
-                last_l1_batch_to_keep >= last_executed_l1_batch,
-                "Attempt to revert already executed blocks"
-            );
+                .ok_or_else(|| {
+                    BlockReverterError::Database("failed to get last executed L1 block".to_string())
+                })?;
+            if last_l1_batch_to_keep < last_executed_l1_batch {
+                return Err(BlockReverterError::RevertExecutedBatch(
+                    last_executed_l1_batch,
+                ));
+            }
         }
 
         // Tree needs to be reverted first to keep state recoverable
         self.rollback_rocks_dbs(last_l1_batch_to_keep, rollback_tree, rollback_sk_cache)
-            .await;
+            .await?;
         if rollback_postgres {
-            self.rollback_postgres(last_l1_batch_to_keep).await;
+            self.rollback_postgres(last_l1_batch_to_keep).await?;
         }
+        Ok(())
     }
 
     async fn rollback_rocks_dbs(
@@ -148,7 +249,21 @@ impl BlockReverter {
         last_l1_batch_to_keep: L1BatchNumber,
         rollback_tree: bool,
         rollback_sk_cache: bool,
-    ) {
+    ) -> Result<(), BlockReverterError> {
+        // Take a point-in-time backup before any in-place mutation so a misjudged revert can be
+        // undone. `tree.save()` and the state-keeper-cache rollback are irreversible otherwise.
+        if self.backup_path.is_some() && (rollback_tree || rollback_sk_cache) {
+            // Tag the snapshot with the last sealed L1 batch, i.e. the state before this rollback.
+            let source_l1_batch = self
+                .connection_pool
+                .access_storage()
+                .await
+                .blocks_dal()
+                .get_sealed_l1_batch_number()
+                .await;
+            self.snapshot_rocks_dbs(source_l1_batch, rollback_tree, rollback_sk_cache)?;
+        }
+
         if rollback_tree {
             let storage_root_hash = self
                 .connection_pool
@@ -157,7 +272,11 @@ impl BlockReverter {
                 .blocks_dal()
                 .get_block_state_root(last_l1_batch_to_keep)
                 .await
-                .expect("failed to fetch root hash for target block");
+                .ok_or_else(|| {
+                    BlockReverterError::Database(
+                        "failed to fetch root hash for target block".to_string(),
+                    )
+                })?;
 
             // Rolling back Merkle tree
             let new_lightweight_tree_path = &self.db_config.new_merkle_tree_ssd_path;
@@ -167,40 +286,159 @@ impl BlockReverter {
                     last_l1_batch_to_keep,
                     new_lightweight_tree_path,
                     storage_root_hash,
-                );
+                )?;
             } else {
                 vlog::info!("New lightweight tree not found; skipping");
             }
         }
 
         if rollback_sk_cache {
-            assert!(
-                Path::new(self.db_config.state_keeper_db_path()).exists(),
-                "Path with state keeper cache DB doesn't exist"
-            );
+            if !Path::new(self.db_config.state_keeper_db_path()).exists() {
+                return Err(BlockReverterError::Database(
+                    "Path with state keeper cache DB doesn't exist".to_string(),
+                ));
+            }
             self.rollback_state_keeper_cache(last_l1_batch_to_keep)
                 .await;
         }
+        Ok(())
+    }
+
+    /// Directory under `backup_path` that holds the snapshot for a given source L1 batch.
+    fn backup_dir(backup_path: &Path, source_l1_batch: L1BatchNumber) -> PathBuf {
+        backup_path.join(format!("rocksdb_backup_l1_batch_{}", source_l1_batch.0))
+    }
+
+    /// Writes a consistent point-in-time copy of the RocksDB stores about to be mutated into the
+    /// configured backup directory, tagged with the source (pre-rollback) L1 batch number.
+    fn snapshot_rocks_dbs(
+        &self,
+        source_l1_batch: L1BatchNumber,
+        snapshot_tree: bool,
+        snapshot_sk_cache: bool,
+    ) -> Result<(), BlockReverterError> {
+        let backup_path = self
+            .backup_path
+            .as_ref()
+            .expect("snapshot_rocks_dbs called without a configured backup path");
+        let backup_dir = Self::backup_dir(backup_path, source_l1_batch);
+        vlog::info!("snapshotting RocksDB stores to {}", backup_dir.display());
+        fs::create_dir_all(&backup_dir).map_err(|e| BlockReverterError::Database(e.to_string()))?;
+
+        if snapshot_tree {
+            let tree_path = Path::new(&self.db_config.new_merkle_tree_ssd_path);
+            if tree_path.exists() {
+                Self::checkpoint_db_dir(tree_path, &backup_dir.join("tree"))?;
+            }
+        }
+        if snapshot_sk_cache {
+            let sk_path = Path::new(self.db_config.state_keeper_db_path());
+            if sk_path.exists() {
+                Self::checkpoint_db_dir(sk_path, &backup_dir.join("state_keeper_cache"))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes a consistent snapshot of the RocksDB store at `src` into `dst` using RocksDB's own
+    /// checkpoint facility, which hard-links the live SSTs and flushes outstanding writes so the
+    /// copy is valid even if the store is open.
+    fn checkpoint_db_dir(src: &Path, dst: &Path) -> Result<(), BlockReverterError> {
+        let options = RocksDbOptions::default();
+        let column_families = DB::list_cf(&options, src)
+            .map_err(|e| BlockReverterError::Database(e.to_string()))?;
+        let db = DB::open_cf(&options, src, column_families)
+            .map_err(|e| BlockReverterError::Database(e.to_string()))?;
+        // `create_checkpoint` requires the destination not to exist yet.
+        Checkpoint::new(&db)
+            .and_then(|checkpoint| checkpoint.create_checkpoint(dst))
+            .map_err(|e| BlockReverterError::Database(e.to_string()))
+    }
+
+    /// Restores the RocksDB stores from the snapshot tagged with `backup_tag` (the last sealed L1
+    /// batch number at the time of a previous rollback), overwriting the current stores.
+    pub fn restore_rocks_dbs(&self, backup_tag: L1BatchNumber) -> Result<(), BlockReverterError> {
+        let backup_path = self.backup_path.as_ref().ok_or_else(|| {
+            BlockReverterError::Database("no backup path configured".to_string())
+        })?;
+        let backup_dir = Self::backup_dir(backup_path, backup_tag);
+        if !backup_dir.exists() {
+            return Err(BlockReverterError::Database(format!(
+                "no RocksDB snapshot found at {}",
+                backup_dir.display()
+            )));
+        }
+        vlog::info!("restoring RocksDB stores from {}", backup_dir.display());
+
+        let tree_backup = backup_dir.join("tree");
+        if tree_backup.exists() {
+            let tree_path = Path::new(&self.db_config.new_merkle_tree_ssd_path);
+            Self::replace_db_dir(&tree_backup, tree_path)?;
+        }
+        let sk_backup = backup_dir.join("state_keeper_cache");
+        if sk_backup.exists() {
+            let sk_path = Path::new(self.db_config.state_keeper_db_path());
+            Self::replace_db_dir(&sk_backup, sk_path)?;
+        }
+        Ok(())
+    }
+
+    /// Recursively copies a RocksDB directory to `dst`.
+    fn copy_db_dir(src: &Path, dst: &Path) -> Result<(), BlockReverterError> {
+        fs::create_dir_all(dst).map_err(|e| BlockReverterError::Database(e.to_string()))?;
+        for entry in fs::read_dir(src).map_err(|e| BlockReverterError::Database(e.to_string()))? {
+            let entry = entry.map_err(|e| BlockReverterError::Database(e.to_string()))?;
+            let target = dst.join(entry.file_name());
+            if entry
+                .file_type()
+                .map_err(|e| BlockReverterError::Database(e.to_string()))?
+                .is_dir()
+            {
+                Self::copy_db_dir(&entry.path(), &target)?;
+            } else {
+                fs::copy(entry.path(), &target)
+                    .map_err(|e| BlockReverterError::Database(e.to_string()))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Replaces the directory at `dst` with a fresh copy of `src`.
+    fn replace_db_dir(src: &Path, dst: &Path) -> Result<(), BlockReverterError> {
+        if dst.exists() {
+            fs::remove_dir_all(dst).map_err(|e| BlockReverterError::Database(e.to_string()))?;
+        }
+        Self::copy_db_dir(src, dst)
     }
 
     fn rollback_new_tree(
         last_l1_batch_to_keep: L1BatchNumber,
         path: impl AsRef<Path>,
         storage_root_hash: H256,
-    ) {
+    ) -> Result<(), BlockReverterError> {
         let db = RocksDB::new(path, true);
         let mut tree = ZkSyncTree::new_lightweight(db);
 
         if tree.block_number() <= last_l1_batch_to_keep.0 {
             vlog::info!("Tree is behind the block to revert to; skipping");
-            return;
+            return Ok(());
         }
         tree.revert_logs(last_l1_batch_to_keep);
 
         vlog::info!("checking match of the tree root hash and root hash from Postgres...");
-        assert_eq!(tree.root_hash(), storage_root_hash);
+        let tree_root = tree.root_hash();
+        if tree_root != storage_root_hash {
+            // Leave RocksDB untouched (no `save()`) so the caller can diagnose without a
+            // half-reverted tree on disk.
+            return Err(BlockReverterError::RootHashMismatch {
+                l1_batch: last_l1_batch_to_keep,
+                tree_root,
+                storage_root: storage_root_hash,
+            });
+        }
         vlog::info!("saving tree changes to disk...");
         tree.save();
+        Ok(())
     }
 
     /// Reverts blocks in the state keeper cache.
@@ -219,8 +457,50 @@ impl BlockReverter {
     }
 
     /// Reverts data in the Postgres database.
-    async fn rollback_postgres(&self, last_l1_batch_to_keep: L1BatchNumber) {
+    ///
+    /// The deletion is performed in windows of `rollback_chunk_size` L1 batches, each committed in
+    /// its own transaction. This bounds the number of rows removed per statement (so a deep
+    /// rollback does not hold locks / bloat WAL for minutes) while keeping every committed state
+    /// consistent and resumable: each chunk removes a whole set of batches, never leaving a
+    /// partially-deleted batch behind.
+    async fn rollback_postgres(
+        &self,
+        last_l1_batch_to_keep: L1BatchNumber,
+    ) -> Result<(), BlockReverterError> {
         vlog::info!("rolling back postgres data...");
+
+        let mut current_last_batch = self
+            .connection_pool
+            .access_storage()
+            .await
+            .blocks_dal()
+            .get_sealed_l1_batch_number()
+            .await;
+
+        while current_last_batch > last_l1_batch_to_keep {
+            // Keep everything at or below the chunk boundary; clamp to the final target.
+            let chunk_boundary = L1BatchNumber(
+                current_last_batch
+                    .0
+                    .saturating_sub(self.rollback_chunk_size)
+                    .max(last_l1_batch_to_keep.0),
+            );
+            vlog::info!(
+                "rolling back postgres chunk: L1 batches ({}, {}]",
+                chunk_boundary,
+                current_last_batch
+            );
+            self.rollback_postgres_chunk(chunk_boundary).await?;
+            current_last_batch = chunk_boundary;
+        }
+        Ok(())
+    }
+
+    /// Deletes every batch strictly above `last_l1_batch_to_keep` within a single transaction.
+    async fn rollback_postgres_chunk(
+        &self,
+        last_l1_batch_to_keep: L1BatchNumber,
+    ) -> Result<(), BlockReverterError> {
         let mut storage = self.connection_pool.access_storage().await;
         let mut transaction = storage.start_transaction().await;
 
@@ -228,7 +508,11 @@ impl BlockReverter {
             .blocks_dal()
             .get_miniblock_range_of_l1_batch(last_l1_batch_to_keep)
             .await
-            .expect("L1 batch should contain at least one miniblock");
+            .ok_or_else(|| {
+                BlockReverterError::Database(
+                    "L1 batch should contain at least one miniblock".to_string(),
+                )
+            })?;
 
         vlog::info!("rolling back transactions state...");
         transaction
@@ -277,24 +561,39 @@ impl BlockReverter {
             .await;
 
         transaction.commit().await;
+        Ok(())
     }
 
     /// Sends revert transaction to L1.
+    ///
+    /// The transaction keeps a fixed `nonce` and is resubmitted with an escalated fee if it is not
+    /// included within `ResubmitConfig::inclusion_timeout_blocks`. Each resubmission bumps both
+    /// `max_priority_fee_per_gas` and `max_fee_per_gas` by the 12.5% minimum required for a valid
+    /// replacement and is capped at `ResubmitConfig::max_fee_per_gas_ceiling`; once the ceiling is
+    /// exceeded the call returns an error instead of looping forever.
     pub async fn send_ethereum_revert_transaction(
         &self,
         last_l1_batch_to_keep: L1BatchNumber,
         priority_fee_per_gas: U256,
         nonce: u64,
-    ) {
+    ) -> Result<(), BlockReverterError> {
         let eth_config = self
             .eth_config
             .as_ref()
             .expect("eth_config is not provided");
 
-        let web3 = Web3::new(Http::new(&eth_config.eth_client_url).unwrap());
+        let web3 = Web3::new(
+            Http::new(&eth_config.eth_client_url)
+                .map_err(|e| BlockReverterError::L1Client(e.to_string()))?,
+        );
         let contract = zksync_contract();
         let signer = PrivateKeySigner::new(eth_config.reverter_private_key);
-        let chain_id = web3.eth().chain_id().await.unwrap().as_u64();
+        let chain_id = web3
+            .eth()
+            .chain_id()
+            .await
+            .map_err(|e| BlockReverterError::L1Client(e.to_string()))?
+            .as_u64();
 
         let data = contract
             .function("revertBlocks")
@@ -302,46 +601,145 @@ impl BlockReverter {
             .encode_input(&[Token::Uint(last_l1_batch_to_keep.0.into())])
             .unwrap();
 
-        let base_fee = web3
-            .eth()
-            .block(BlockId::Number(BlockNumber::Pending))
-            .await
-            .unwrap()
-            .unwrap()
-            .base_fee_per_gas
-            .unwrap();
-
-        let tx = TransactionParameters {
-            to: eth_config.validator_timelock_addr.into(),
-            data,
-            chain_id,
-            nonce: nonce.into(),
-            max_priority_fee_per_gas: priority_fee_per_gas,
-            max_fee_per_gas: base_fee + priority_fee_per_gas,
-            gas: 5_000_000.into(),
-            ..Default::default()
-        };
-
-        let signed_tx = signer.sign_transaction(tx).await.unwrap();
-        let hash = web3
+        // Estimate the gas limit once (the calldata does not change across resubmissions) and apply
+        // the configured safety margin.
+        let estimated_gas = web3
             .eth()
-            .send_raw_transaction(signed_tx.into())
+            .estimate_gas(
+                CallRequest {
+                    from: Some(eth_config.reverter_address),
+                    to: Some(eth_config.validator_timelock_addr),
+                    data: Some(data.clone().into()),
+                    ..Default::default()
+                },
+                None,
+            )
             .await
-            .unwrap();
+            .map_err(|e| BlockReverterError::L1Client(e.to_string()))?;
+        let gas_limit = Self::apply_gas_multiplier(
+            estimated_gas,
+            eth_config.resubmit_config.gas_limit_multiplier,
+        );
 
+        let mut priority_fee_per_gas = priority_fee_per_gas;
+        let mut prev_max_fee_per_gas = U256::zero();
+        let mut attempt: u32 = 0;
         loop {
-            if let Some(receipt) = web3.eth().transaction_receipt(hash).await.unwrap() {
-                assert_eq!(receipt.status, Some(1.into()), "revert transaction failed");
-                vlog::info!("revert transaction has completed");
-                return;
+            let base_fee = web3
+                .eth()
+                .block(BlockId::Number(BlockNumber::Pending))
+                .await
+                .map_err(|e| BlockReverterError::L1Client(e.to_string()))?
+                .and_then(|block| block.base_fee_per_gas)
+                .ok_or_else(|| {
+                    BlockReverterError::L1Client("pending block has no base fee".to_string())
+                })?;
+            // A valid replacement must raise `max_fee_per_gas` by at least 12.5%, not just the
+            // tip; with a stable base fee, `base + 1.125·tip` falls short of that floor.
+            let max_fee_per_gas =
+                cmp::max(base_fee + priority_fee_per_gas, Self::bump_fee(prev_max_fee_per_gas));
+            if max_fee_per_gas > eth_config.resubmit_config.max_fee_per_gas_ceiling {
+                return Err(BlockReverterError::L1Client(format!(
+                    "max_fee_per_gas {} exceeds the configured ceiling {}; aborting revert",
+                    max_fee_per_gas, eth_config.resubmit_config.max_fee_per_gas_ceiling
+                )));
+            }
+            prev_max_fee_per_gas = max_fee_per_gas;
+
+            let tx = TransactionParameters {
+                to: eth_config.validator_timelock_addr.into(),
+                data: data.clone(),
+                chain_id,
+                nonce: nonce.into(),
+                max_priority_fee_per_gas: priority_fee_per_gas,
+                max_fee_per_gas,
+                gas: gas_limit,
+                ..Default::default()
+            };
+
+            let signed_tx = signer
+                .sign_transaction(tx)
+                .await
+                .map_err(|e| BlockReverterError::Signing(e.to_string()))?;
+            let hash = web3
+                .eth()
+                .send_raw_transaction(signed_tx.into())
+                .await
+                .map_err(|e| BlockReverterError::Signing(e.to_string()))?;
+            if attempt == 0 {
+                vlog::info!("revert transaction sent with nonce {}", nonce);
             } else {
+                vlog::info!(
+                    "revert transaction resubmitted (attempt {}) with max_fee_per_gas {}",
+                    attempt,
+                    max_fee_per_gas
+                );
+            }
+
+            let deadline_block = web3
+                .eth()
+                .block_number()
+                .await
+                .map_err(|e| BlockReverterError::L1Client(e.to_string()))?
+                .as_u64()
+                + eth_config.resubmit_config.inclusion_timeout_blocks;
+            loop {
+                if let Some(receipt) = web3
+                    .eth()
+                    .transaction_receipt(hash)
+                    .await
+                    .map_err(|e| BlockReverterError::L1Client(e.to_string()))?
+                {
+                    if receipt.status != Some(1.into()) {
+                        return Err(BlockReverterError::Signing(
+                            "revert transaction failed".to_string(),
+                        ));
+                    }
+                    vlog::info!("revert transaction has completed");
+                    return Ok(());
+                }
+
+                if web3
+                    .eth()
+                    .block_number()
+                    .await
+                    .map_err(|e| BlockReverterError::L1Client(e.to_string()))?
+                    .as_u64()
+                    >= deadline_block
+                {
+                    vlog::warn!(
+                        "revert transaction not included within {} blocks; escalating fee",
+                        eth_config.resubmit_config.inclusion_timeout_blocks
+                    );
+                    // Bump the priority fee by the 12.5% replacement minimum (rounding up) and retry.
+                    priority_fee_per_gas = Self::bump_fee(priority_fee_per_gas);
+                    attempt += 1;
+                    break;
+                }
+
                 vlog::info!("waiting for L1 transaction confirmation...");
                 sleep(Duration::from_secs(5)).await;
             }
         }
     }
 
-    async fn get_l1_batch_number_from_contract(&self, op: AggregatedActionType) -> L1BatchNumber {
+    /// Bumps a fee by the 12.5% minimum required for a valid replacement transaction, rounding up.
+    fn bump_fee(fee: U256) -> U256 {
+        // `ceil(fee * 1.125) = ceil(fee * 9 / 8)`.
+        (fee * 9 + 7) / 8
+    }
+
+    /// Applies a floating-point safety multiplier to an estimated gas limit.
+    fn apply_gas_multiplier(estimated_gas: U256, multiplier: f64) -> U256 {
+        // Scale through a fixed-point factor to avoid precision loss on large gas values.
+        let scaled = (multiplier * 1000.0).round() as u64;
+        estimated_gas * U256::from(scaled) / U256::from(1000u64)
+    }
+
+    async fn get_l1_batch_number_from_contract(
+        &self,
+        op: AggregatedActionType,
+    ) -> Result<L1BatchNumber, BlockReverterError> {
         let function_name = match op {
             AggregatedActionType::CommitBlocks => "getTotalBlocksCommitted",
             AggregatedActionType::PublishProofBlocksOnchain => "getTotalBlocksVerified",
@@ -352,7 +750,10 @@ impl BlockReverter {
             .as_ref()
             .expect("eth_config is not provided");
 
-        let web3 = Web3::new(Http::new(&eth_config.eth_client_url).unwrap());
+        let web3 = Web3::new(
+            Http::new(&eth_config.eth_client_url)
+                .map_err(|e| BlockReverterError::L1Client(e.to_string()))?,
+        );
         let contract = {
             let abi = zksync_contract();
             let contract_address = eth_config.diamond_proxy_addr;
@@ -362,22 +763,22 @@ impl BlockReverter {
         let block_number: U256 = contract
             .query(function_name, (), None, Options::default(), None)
             .await
-            .unwrap();
+            .map_err(|e| BlockReverterError::L1Client(e.to_string()))?;
 
-        L1BatchNumber(block_number.as_u32())
+        Ok(L1BatchNumber(block_number.as_u32()))
     }
 
     /// Returns suggested values for rollback.
-    pub async fn suggested_values(&self) -> SuggestedRollbackValues {
+    pub async fn suggested_values(&self) -> Result<SuggestedRollbackValues, BlockReverterError> {
         let last_committed_l1_batch_number = self
             .get_l1_batch_number_from_contract(AggregatedActionType::CommitBlocks)
-            .await;
+            .await?;
         let last_verified_l1_batch_number = self
             .get_l1_batch_number_from_contract(AggregatedActionType::PublishProofBlocksOnchain)
-            .await;
+            .await?;
         let last_executed_l1_batch_number = self
             .get_l1_batch_number_from_contract(AggregatedActionType::ExecuteBlocks)
-            .await;
+            .await?;
         vlog::info!(
             "Last L1 batch numbers on contract: committed {}, verified {}, executed {}",
             last_committed_l1_batch_number,
@@ -392,19 +793,22 @@ impl BlockReverter {
 
         let priority_fee = eth_config.default_priority_fee_per_gas;
 
-        let web3 = Web3::new(Http::new(&eth_config.eth_client_url).unwrap());
+        let web3 = Web3::new(
+            Http::new(&eth_config.eth_client_url)
+                .map_err(|e| BlockReverterError::L1Client(e.to_string()))?,
+        );
         let nonce = web3
             .eth()
             .transaction_count(eth_config.reverter_address, Some(BlockNumber::Pending))
             .await
-            .unwrap()
+            .map_err(|e| BlockReverterError::L1Client(e.to_string()))?
             .as_u64();
 
-        SuggestedRollbackValues {
+        Ok(SuggestedRollbackValues {
             last_executed_l1_batch_number,
             nonce,
             priority_fee,
-        }
+        })
     }
 
     /// Clears failed L1 transactions
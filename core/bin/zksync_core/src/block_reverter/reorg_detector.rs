@@ -0,0 +1,143 @@
+use async_trait::async_trait;
+
+use zksync_dal::ConnectionPool;
+use zksync_types::{L1BatchNumber, H256};
+
+use super::{BlockReverter, BlockReverterError, BlockReverterFlags, L1ExecutedBatchesRevert};
+
+/// Source of the canonical state root hashes, i.e. the main node.
+///
+/// Abstracted behind a trait so the detector can be driven by a real JSON-RPC client in production
+/// and by an in-memory fixture in tests.
+#[async_trait]
+pub trait MainNodeClient: Send + Sync {
+    /// Returns the state root hash reported by the main node for the given L1 batch, or `None` if
+    /// the main node does not know about the batch yet.
+    async fn l1_batch_state_root(&self, number: L1BatchNumber) -> Option<H256>;
+}
+
+/// Detects a reorg of the main node relative to the external node and drives the rollback of the
+/// EN databases to the common ancestor.
+///
+/// The common ancestor is the highest L1 batch number for which the EN's local state root hash
+/// matches the one reported by the main node: scan backward until the roots agree, then
+/// binary-search the divergent range so the whole history need not be fetched.
+#[derive(Debug)]
+pub struct ReorgDetector<C> {
+    main_node_client: C,
+    pool: ConnectionPool,
+    reverter: BlockReverter,
+}
+
+impl<C: MainNodeClient> ReorgDetector<C> {
+    /// # Panics
+    ///
+    /// Panics if `reverter` was not built with [`L1ExecutedBatchesRevert::Allowed`]: the detector
+    /// exists to roll the EN back past batches the main node has already executed on L1, which is
+    /// exactly the revert `Disallowed` refuses, so constructing it with that mode would make
+    /// `check_and_rollback` fail with [`BlockReverterError::RevertExecutedBatch`] on such reorgs.
+    pub fn new(main_node_client: C, pool: ConnectionPool, reverter: BlockReverter) -> Self {
+        assert!(
+            matches!(
+                reverter.executed_batches_revert_mode(),
+                L1ExecutedBatchesRevert::Allowed
+            ),
+            "ReorgDetector requires a BlockReverter built with L1ExecutedBatchesRevert::Allowed"
+        );
+        Self {
+            main_node_client,
+            pool,
+            reverter,
+        }
+    }
+
+    /// Checks whether the batch root stored locally matches the one reported by the main node.
+    async fn root_matches(&self, number: L1BatchNumber) -> bool {
+        let local_root = self
+            .pool
+            .access_storage()
+            .await
+            .blocks_dal()
+            .get_block_state_root(number)
+            .await;
+        match (local_root, self.main_node_client.l1_batch_state_root(number).await) {
+            (Some(local), Some(remote)) => local == remote,
+            // A batch the main node no longer knows about is, by definition, diverged.
+            _ => false,
+        }
+    }
+
+    /// Returns the highest L1 batch number whose state root still agrees with the main node, or
+    /// `None` if even the earliest batch diverges.
+    async fn find_common_ancestor(&self, last_local_batch: L1BatchNumber) -> Option<L1BatchNumber> {
+        if self.root_matches(last_local_batch).await {
+            return Some(last_local_batch);
+        }
+
+        // Bounded backward scan to bracket the divergence, doubling the step each time so we only
+        // fetch O(log n) batches before narrowing in.
+        let mut step = 1u32;
+        let mut hi = last_local_batch; // Known to diverge.
+        let mut lo = loop {
+            let candidate = last_local_batch.0.saturating_sub(step);
+            let candidate = L1BatchNumber(candidate);
+            if self.root_matches(candidate).await {
+                break candidate; // Known to agree.
+            }
+            hi = candidate;
+            if candidate.0 == 0 {
+                return None;
+            }
+            step = step.saturating_mul(2);
+        };
+
+        // Binary search for the boundary in `[lo, hi)`: `lo` agrees, `hi` diverges.
+        while hi.0 - lo.0 > 1 {
+            let mid = L1BatchNumber(lo.0 + (hi.0 - lo.0) / 2);
+            if self.root_matches(mid).await {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        Some(lo)
+    }
+
+    /// Detects a divergence starting from the EN's last local L1 batch and, if found, rolls the EN
+    /// databases back to the common ancestor.
+    pub async fn check_and_rollback(&self) -> Result<(), BlockReverterError> {
+        let last_local_batch = self
+            .pool
+            .access_storage()
+            .await
+            .blocks_dal()
+            .get_sealed_l1_batch_number()
+            .await;
+
+        let Some(common_ancestor) = self.find_common_ancestor(last_local_batch).await else {
+            vlog::warn!("No common ancestor with the main node within the scanned range");
+            return Ok(());
+        };
+
+        if common_ancestor == last_local_batch {
+            vlog::info!("No reorg detected; EN is consistent with the main node");
+            return Ok(());
+        }
+
+        let reverted_batches = last_local_batch.0 - common_ancestor.0;
+        vlog::info!(
+            "Reorg detected: EN diverged from the main node above L1 batch {}; \
+             reverting {} batch(es) back to the common ancestor",
+            common_ancestor,
+            reverted_batches
+        );
+        self.reverter
+            .rollback_db(
+                common_ancestor,
+                BlockReverterFlags::POSTGRES
+                    | BlockReverterFlags::TREE
+                    | BlockReverterFlags::SK_CACHE,
+            )
+            .await
+    }
+}
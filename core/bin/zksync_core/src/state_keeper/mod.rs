@@ -0,0 +1,16 @@
+pub(crate) mod keeper;
+pub(crate) mod seal_criteria;
+pub(crate) mod types;
+pub(crate) mod updates;
+
+pub mod pending_set;
+pub mod sync;
+pub mod timestamp_backpressure;
+pub mod timestamp_clamp;
+pub mod timestamp_ms;
+pub mod timestamp_provider;
+pub mod tx_ban;
+pub mod tx_ordering;
+
+#[cfg(test)]
+mod tests;
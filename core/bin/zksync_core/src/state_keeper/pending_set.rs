@@ -0,0 +1,108 @@
+use std::collections::VecDeque;
+
+use zksync_types::{Transaction, U256};
+
+use crate::state_keeper::tx_ban::TxBanManager;
+use crate::state_keeper::tx_ordering::{ranked_iter, TxOrderingMode};
+
+/// A bounded view over the mempool's pending transactions.
+///
+/// Under load the mempool can hold far more pending txs than a batch can ever consume, so
+/// materializing and ranking all of them per poll is wasteful. This keeps at most `max_pending`
+/// candidates and exposes two access paths:
+///
+/// * an *unordered* fast path, used when the full ranking is not needed (e.g. slots are nearly full
+///   and only a couple more txs can fit);
+/// * a *ranked* path that lazily ranks only the candidates actually consumed out of the bounded
+///   window, via [`ranked_iter`].
+///
+/// Neither path ranks more than `max_pending` candidates, so the cost is bounded regardless of how
+/// large the backing mempool is.
+///
+/// Nothing in this crate constructs a `BoundedPendingSet` outside tests yet: the mempool-polling
+/// loop that would own one lives in the seal loop, which isn't part of this module.
+#[derive(Debug)]
+pub struct BoundedPendingSet {
+    max_pending: usize,
+    txs: VecDeque<Transaction>,
+}
+
+impl BoundedPendingSet {
+    pub fn new(max_pending: usize) -> Self {
+        assert!(max_pending > 0, "max_pending must be positive");
+        Self {
+            max_pending,
+            txs: VecDeque::with_capacity(max_pending),
+        }
+    }
+
+    /// Adds a transaction to the window. Returns `false` (and drops the tx) if the window is full,
+    /// bounding the amount of state the IO layer ever holds.
+    pub fn push(&mut self, tx: Transaction) -> bool {
+        if self.txs.len() >= self.max_pending {
+            return false;
+        }
+        self.txs.push_back(tx);
+        true
+    }
+
+    pub fn len(&self) -> usize {
+        self.txs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.txs.is_empty()
+    }
+
+    /// Unordered fast path: yields candidates in arrival order without any sorting.
+    pub fn iter_unordered(&self) -> impl Iterator<Item = &Transaction> {
+        self.txs.iter()
+    }
+
+    /// Ranked path: lazily ranks the bounded window according to `mode` and yields the candidates
+    /// best-first. Ranking cost is bounded by `max_pending`, not the full mempool, and a caller
+    /// that stops consuming early never pays for ranking candidates it didn't reach (see
+    /// [`ranked_iter`]).
+    pub fn iter_ranked(
+        &self,
+        mode: TxOrderingMode,
+        base_fee: U256,
+    ) -> impl Iterator<Item = Transaction> {
+        ranked_iter(self.txs.iter().cloned().collect(), mode, base_fee)
+    }
+
+    /// Ranked path that additionally skips any transaction currently banned by `bans`, lazily
+    /// dropping bans whose window has elapsed as a side effect (see [`TxBanManager::is_banned`]).
+    /// The IO layer should prefer this over [`Self::iter_ranked`] once a [`TxBanManager`] is in use,
+    /// so a tx that keeps forcing a rollback is not re-offered every poll.
+    pub fn iter_ranked_unbanned<'a>(
+        &'a self,
+        mode: TxOrderingMode,
+        base_fee: U256,
+        bans: &'a mut TxBanManager,
+        now: u64,
+    ) -> impl Iterator<Item = Transaction> + 'a {
+        ranked_iter(self.txs.iter().cloned().collect(), mode, base_fee)
+            .filter(move |tx| !bans.is_banned(&tx.hash(), now))
+    }
+
+    /// Drives candidates through `offer` (which executes the tx and returns whether the batch will
+    /// now seal), stopping as soon as sealing is signaled so remaining candidates are never offered
+    /// to the VM *or ranked*: [`ranked_iter`] only ranks a candidate once it's pulled, so once
+    /// `offer` signals a seal the rest of the window is recovered unranked via
+    /// [`RankedIter::into_unranked_remainder`] and left for the next poll to re-rank from scratch.
+    pub fn drain_until_seal<F>(&mut self, mode: TxOrderingMode, base_fee: U256, mut offer: F)
+    where
+        F: FnMut(&Transaction) -> bool,
+    {
+        let mut ranked = ranked_iter(self.txs.drain(..).collect(), mode, base_fee);
+        let mut leftover = Vec::new();
+        while let Some(tx) = ranked.next() {
+            if offer(&tx) {
+                leftover = ranked.into_unranked_remainder();
+                break;
+            }
+        }
+        self.txs = leftover.into();
+    }
+}
@@ -0,0 +1,123 @@
+use zksync_config::configs::chain::StateKeeperConfig;
+
+use crate::state_keeper::seal_criteria::{SealCriterion, SealResolution};
+use crate::state_keeper::types::ExecutionMetricsForCriteria;
+
+/// Per-batch ceilings for each independent resource dimension tracked by [`CostCriterion`].
+///
+/// Each dimension saturates independently, so a batch can seal on any one of them.
+#[derive(Debug, Clone, Copy)]
+pub struct CostLimits {
+    pub computational_gas: u32,
+    pub storage_writes: usize,
+    pub pubdata_bytes: usize,
+    pub l2_to_l1_log_queries: usize,
+    pub total_log_queries: usize,
+}
+
+impl CostLimits {
+    /// Reads the configured per-dimension ceilings from the state keeper config.
+    pub fn from_config(config: &StateKeeperConfig) -> Self {
+        Self {
+            computational_gas: config.max_circuits_computational_gas_per_batch,
+            storage_writes: config.max_storage_writes_per_batch,
+            pubdata_bytes: config.max_pubdata_bytes_per_batch,
+            l2_to_l1_log_queries: config.max_l2_to_l1_log_queries_per_batch,
+            total_log_queries: config.max_total_log_queries_per_batch,
+        }
+    }
+}
+
+/// A cost vector along the dimensions guarded by [`CostCriterion`]. Maintained as a running
+/// accumulation in `UpdatesManager` and computed per candidate transaction before a sealing
+/// decision.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CostVector {
+    pub computational_gas: u32,
+    pub storage_writes: usize,
+    pub pubdata_bytes: usize,
+    pub l2_to_l1_log_queries: usize,
+    pub total_log_queries: usize,
+}
+
+impl CostVector {
+    /// Derives the cost vector of a single transaction from its execution metrics.
+    pub fn from_metrics(metrics: &ExecutionMetricsForCriteria) -> Self {
+        let m = &metrics.execution_metrics;
+        Self {
+            computational_gas: m.computational_gas_used,
+            storage_writes: m.initial_storage_writes + m.repeated_storage_writes,
+            pubdata_bytes: m.published_bytecode_bytes + m.l2_l1_long_messages,
+            l2_to_l1_log_queries: m.l2_to_l1_logs,
+            total_log_queries: m.total_log_queries,
+        }
+    }
+
+    fn saturating_add(self, other: Self) -> Self {
+        Self {
+            computational_gas: self.computational_gas.saturating_add(other.computational_gas),
+            storage_writes: self.storage_writes.saturating_add(other.storage_writes),
+            pubdata_bytes: self.pubdata_bytes.saturating_add(other.pubdata_bytes),
+            l2_to_l1_log_queries: self
+                .l2_to_l1_log_queries
+                .saturating_add(other.l2_to_l1_log_queries),
+            total_log_queries: self.total_log_queries.saturating_add(other.total_log_queries),
+        }
+    }
+
+    /// Returns `true` if any dimension of `self` exceeds the corresponding ceiling.
+    fn exceeds(&self, limits: &CostLimits) -> bool {
+        self.computational_gas > limits.computational_gas
+            || self.storage_writes > limits.storage_writes
+            || self.pubdata_bytes > limits.pubdata_bytes
+            || self.l2_to_l1_log_queries > limits.l2_to_l1_log_queries
+            || self.total_log_queries > limits.total_log_queries
+    }
+}
+
+/// Seals a batch when adding the candidate transaction would push any resource dimension past its
+/// per-batch ceiling.
+///
+/// If the transaction alone already exceeds a limit, the batch is sealed without it
+/// (`ExcludeAndSeal`); this criterion does not mark such a transaction unexecutable. If only the
+/// accumulation does, the transaction is included and the batch is sealed after it
+/// (`IncludeAndSeal`). This mirrors the existing
+/// [`GasCriterion`](super::GasCriterion) / [`SlotsCriterion`](super::SlotsCriterion) shape but
+/// guards several saturating dimensions at once.
+///
+/// Implementing [`SealCriterion`] makes this a drop-in peer of `GasCriterion`/`SlotsCriterion`, but
+/// actually including it in the active seal-criteria list is a change to wherever that list is
+/// assembled (`seal_criteria::ConditionalSealer`'s default construction), which is outside this
+/// module.
+#[derive(Debug)]
+pub struct CostCriterion;
+
+impl SealCriterion for CostCriterion {
+    fn should_seal(
+        &self,
+        config: &StateKeeperConfig,
+        _block_open_timestamp_ms: u128,
+        _tx_count: usize,
+        block_cost: &ExecutionMetricsForCriteria,
+        tx_cost: &ExecutionMetricsForCriteria,
+    ) -> SealResolution {
+        let limits = CostLimits::from_config(config);
+        let tx_vector = CostVector::from_metrics(tx_cost);
+
+        if tx_vector.exceeds(&limits) {
+            // The transaction can never fit in an empty batch along some dimension.
+            return SealResolution::ExcludeAndSeal;
+        }
+
+        let accumulated = CostVector::from_metrics(block_cost).saturating_add(tx_vector);
+        if accumulated.exceeds(&limits) {
+            SealResolution::IncludeAndSeal
+        } else {
+            SealResolution::NoSeal
+        }
+    }
+
+    fn prom_criterion_name(&self) -> &'static str {
+        "cost"
+    }
+}
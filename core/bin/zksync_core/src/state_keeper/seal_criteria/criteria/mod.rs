@@ -0,0 +1,7 @@
+mod cost;
+mod gas;
+mod slots;
+
+pub use cost::{CostCriterion, CostLimits, CostVector};
+pub use gas::GasCriterion;
+pub use slots::SlotsCriterion;
@@ -0,0 +1,17 @@
+//! Concurrency primitives for the state keeper.
+//!
+//! Under `cfg(loom)` these re-export `loom`'s instrumented shims so the seal-loop harness can be
+//! driven by `loom::model`, which exhaustively explores thread interleavings. In normal builds they
+//! are the corresponding `std` types, so there is zero runtime cost outside of loom tests.
+
+#[cfg(loom)]
+pub use loom::sync::{
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    Arc, Mutex,
+};
+
+#[cfg(not(loom))]
+pub use std::sync::{
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    Arc, Mutex,
+};
@@ -21,7 +21,7 @@ use zksync_types::{
     fee::Fee,
     l2::L2Tx,
     transaction_request::PaymasterParams,
-    tx::tx_execution_info::{TxExecutionStatus, VmExecutionLogs},
+    tx::tx_execution_info::{ExecutionMetrics, TxExecutionStatus, VmExecutionLogs},
     vm_trace::{VmExecutionTrace, VmTrace},
     zk_evm::aux_structures::{LogQuery, Timestamp},
     zk_evm::block_properties::BlockProperties,
@@ -39,16 +39,22 @@ use crate::gas_tracker::constants::{
 };
 use crate::state_keeper::{
     keeper::POLL_WAIT_DURATION,
+    pending_set::BoundedPendingSet,
     seal_criteria::{
-        criteria::{GasCriterion, SlotsCriterion},
+        criteria::{CostCriterion, GasCriterion, SlotsCriterion},
         ConditionalSealer, SealManager,
     },
+    tx_ban::TxBanManager,
+    tx_ordering::TxOrderingMode,
     types::ExecutionMetricsForCriteria,
     updates::UpdatesManager,
 };
 
 mod tester;
 
+#[cfg(loom)]
+mod loom;
+
 pub(super) static BASE_SYSTEM_CONTRACTS: Lazy<BaseSystemContracts> =
     Lazy::new(BaseSystemContracts::load_from_disk);
 
@@ -309,6 +315,83 @@ async fn sealed_by_gas() {
         .run(sealer).await;
 }
 
+#[tokio::test]
+async fn sealed_by_cost_dimension() {
+    // Limits are generous on every dimension except storage writes, so the batch seals on writes
+    // alone even though L1 gas and slot count are nowhere near their ceilings.
+    let config = StateKeeperConfig {
+        transaction_slots: 100,
+        max_storage_writes_per_batch: 1,
+        ..Default::default()
+    };
+    let conditional_sealer = Some(ConditionalSealer::with_sealers(
+        config,
+        vec![Box::new(CostCriterion)],
+    ));
+    let sealer = SealManager::custom(
+        conditional_sealer,
+        vec![Box::new(|_| false)],
+        vec![Box::new(|updates| {
+            updates.miniblock.executed_transactions.len() == 1
+        })],
+    );
+
+    // A single storage write per tx; `CostVector::exceeds` only fires on strict `>`, so the first
+    // tx alone (accumulated = 1) stays under the ceiling of 1 and only the second tx pushes the
+    // accumulated write count past it, driving the seal on the storage-writes dimension.
+    let execution_result = successful_exec_with_metrics(ExecutionMetricsForCriteria {
+        l1_gas: Default::default(),
+        execution_metrics: ExecutionMetrics {
+            initial_storage_writes: 1,
+            ..Default::default()
+        },
+    });
+
+    TestScenario::new()
+        .next_tx("First tx", random_tx(1), execution_result.clone())
+        .miniblock_sealed("Miniblock 1")
+        .next_tx("Second tx", random_tx(2), execution_result)
+        .miniblock_sealed("Miniblock 2")
+        .batch_sealed("Batch sealed once storage writes exceed the per-batch ceiling")
+        .run(sealer)
+        .await;
+}
+
+/// Exercises `TxBanManager` wired into the IO layer's `BoundedPendingSet`: a tx that crosses the
+/// ban threshold stops being offered by `iter_ranked_unbanned`, and is offered again once its ban
+/// window elapses.
+#[test]
+fn pending_set_skips_banned_tx_until_window_elapses() {
+    let mut pending = BoundedPendingSet::new(4);
+    let mut bans = TxBanManager::new(2, 10);
+
+    let banned_tx = random_tx(1);
+    let other_tx = random_tx(2);
+    pending.push(banned_tx.clone());
+    pending.push(other_tx.clone());
+
+    // Two rollbacks of the same tx cross the ban threshold.
+    assert!(!bans.record_offense(banned_tx.hash(), 0), "first offense should not ban");
+    assert!(bans.record_offense(banned_tx.hash(), 5), "second offense should ban");
+
+    let eligible: Vec<H256> = pending
+        .iter_ranked_unbanned(TxOrderingMode::Fifo, U256::zero(), &mut bans, 5)
+        .map(|tx| tx.hash())
+        .collect();
+    assert_eq!(
+        eligible,
+        vec![other_tx.hash()],
+        "banned tx must be filtered out of the pending set"
+    );
+
+    // Ban window (10) elapsed relative to the banning clock value (5).
+    let eligible: Vec<H256> = pending
+        .iter_ranked_unbanned(TxOrderingMode::Fifo, U256::zero(), &mut bans, 15)
+        .map(|tx| tx.hash())
+        .collect();
+    assert_eq!(eligible, vec![banned_tx.hash(), other_tx.hash()]);
+}
+
 #[tokio::test]
 async fn sealed_by_gas_then_by_num_tx() {
     let config = StateKeeperConfig {
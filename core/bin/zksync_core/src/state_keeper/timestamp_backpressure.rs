@@ -0,0 +1,99 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use tokio::time::sleep;
+
+use crate::state_keeper::timestamp_ms::MillisTimestamp;
+
+// Neither `apply_future_backpressure` nor `calibrate_to_wall_clock` is called outside tests yet —
+// the sealer invoking them before finalizing a miniblock and during its periodic tick,
+// respectively, is a change to the seal loop, which isn't part of this module.
+
+/// Wall-clock source, abstracted so the harness can feed a controlled clock.
+pub trait WallClock: Send + Sync {
+    /// Current wall-clock time in milliseconds since the UNIX epoch.
+    fn now_millis(&self) -> u64;
+}
+
+/// Real system clock.
+#[derive(Debug, Default)]
+pub struct SystemWallClock;
+
+impl WallClock for SystemWallClock {
+    fn now_millis(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system time is before UNIX epoch")
+            .as_millis() as u64
+    }
+}
+
+/// Applies backpressure before a miniblock with `candidate` timestamp is finalized.
+///
+/// When the computed timestamp runs ahead of wall-clock time (e.g. under an externally-fed provider
+/// or when interval accumulation outpaces real time), sealing immediately would produce a block
+/// that L1 or verifiers reject as "too far in the future". This waits until the clock catches up to
+/// within `max_future_drift` of the candidate rather than emitting the block early.
+pub async fn apply_future_backpressure(
+    candidate: MillisTimestamp,
+    max_future_drift: Duration,
+    clock: &impl WallClock,
+) {
+    let drift_ms = max_future_drift.as_millis() as u64;
+    loop {
+        let now = clock.now_millis();
+        if candidate.as_millis() <= now + drift_ms {
+            return;
+        }
+        let wait_ms = candidate.as_millis() - (now + drift_ms);
+        sleep(Duration::from_millis(wait_ms)).await;
+    }
+}
+
+/// Periodic calibration: if the internal timestamp counter has diverged from wall clock beyond the
+/// allowed drift, re-sync it to wall clock so steady-state cadence tracks real time. Returns the
+/// (possibly re-synced) timestamp to use as the new counter base.
+pub fn calibrate_to_wall_clock(
+    internal: MillisTimestamp,
+    max_future_drift: Duration,
+    clock: &impl WallClock,
+) -> MillisTimestamp {
+    let now = clock.now_millis();
+    let drift_ms = max_future_drift.as_millis() as u64;
+    if internal.as_millis() > now + drift_ms {
+        vlog::info!(
+            "internal timestamp counter drifted {}ms ahead of wall clock; recalibrating",
+            internal.as_millis() - now
+        );
+        MillisTimestamp(now)
+    } else {
+        internal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedClock(u64);
+    impl WallClock for FixedClock {
+        fn now_millis(&self) -> u64 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn calibration_resyncs_when_ahead() {
+        let clock = FixedClock(1_000);
+        let drift = Duration::from_millis(100);
+        // 500ms ahead of now+drift -> recalibrated to now.
+        assert_eq!(
+            calibrate_to_wall_clock(MillisTimestamp(1_600), drift, &clock).as_millis(),
+            1_000
+        );
+        // Within drift -> left untouched.
+        assert_eq!(
+            calibrate_to_wall_clock(MillisTimestamp(1_050), drift, &clock).as_millis(),
+            1_050
+        );
+    }
+}
@@ -0,0 +1,63 @@
+//! Monotonic clamping for miniblock timestamps.
+//!
+//! A backward system-clock step (NTP correction, VM migration) can make a freshly sourced
+//! timestamp go backwards. Rather than hard-failing the sealer with an assertion, we clamp the
+//! value forward and record how far the clock regressed, keeping block production alive under clock
+//! skew. This mirrors the async-timestamp race handling where a decreasing timestamp is tolerated
+//! rather than panicking.
+//!
+//! Neither function is called outside tests yet: the sealer would call them around wherever it
+//! currently sources a miniblock timestamp, which belongs to the seal loop and isn't part of this
+//! module.
+
+/// Clamps the candidate timestamp of a regular miniblock so it strictly exceeds the previous
+/// miniblock's timestamp. Returns the (possibly clamped) timestamp.
+pub fn clamp_miniblock_timestamp(candidate: u64, prev: u64) -> u64 {
+    if candidate <= prev {
+        report_regression(prev - candidate + 1);
+        prev + 1
+    } else {
+        candidate
+    }
+}
+
+/// Clamps the fictive (batch-closing) miniblock timestamp so it does not decrease relative to the
+/// stored latest miniblock timestamp, bumping by one when it would.
+pub fn clamp_fictive_miniblock_timestamp(candidate: u64, latest_miniblock_timestamp: u64) -> u64 {
+    if candidate <= latest_miniblock_timestamp {
+        report_regression(latest_miniblock_timestamp - candidate + 1);
+        latest_miniblock_timestamp + 1
+    } else {
+        candidate
+    }
+}
+
+fn report_regression(regressed_by: u64) {
+    vlog::warn!(
+        "miniblock timestamp regressed by {} second(s); clamping forward to stay monotonic",
+        regressed_by
+    );
+    metrics::counter!("server.state_keeper.timestamp_clamped", 1);
+    metrics::histogram!(
+        "server.state_keeper.timestamp_regression_seconds",
+        regressed_by as f64
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regular_timestamp_is_clamped_forward() {
+        assert_eq!(clamp_miniblock_timestamp(10, 5), 10, "forward time is kept");
+        assert_eq!(clamp_miniblock_timestamp(5, 5), 6, "equal time is bumped");
+        assert_eq!(clamp_miniblock_timestamp(3, 5), 6, "backward time is clamped");
+    }
+
+    #[test]
+    fn fictive_timestamp_never_decreases() {
+        assert_eq!(clamp_fictive_miniblock_timestamp(9, 9), 10);
+        assert_eq!(clamp_fictive_miniblock_timestamp(12, 9), 12);
+    }
+}
@@ -0,0 +1,89 @@
+//! Millisecond-precision miniblock timestamps.
+//!
+//! Miniblock timestamps were historically whole seconds, capping the effective block cadence at one
+//! per second. Carrying milliseconds internally enables high-frequency setups and finer-grained
+//! block times. Downstream consumers and L1 still expect seconds, so [`MillisTimestamp::as_secs`]
+//! truncates (rounds down) at the boundary; see [`MillisTimestamp::as_secs`] for the rounding
+//! contract.
+//!
+//! Miniblock timestamps still flow through the sealer as whole seconds today — switching the
+//! sealer to carry a [`MillisTimestamp`] internally and enforce [`MiniblockTimestampInterval`] is a
+//! change to the seal loop, which isn't part of this module.
+
+/// A miniblock timestamp measured in milliseconds since the UNIX epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MillisTimestamp(pub u64);
+
+impl MillisTimestamp {
+    pub fn from_secs(secs: u64) -> Self {
+        Self(secs * 1_000)
+    }
+
+    /// Seconds accessor for downstream consumers and L1. Truncates toward zero, i.e. a timestamp of
+    /// `1_999` ms maps to `1` second. Truncation (rather than rounding) guarantees the
+    /// second-granular value never runs ahead of the millisecond one.
+    pub fn as_secs(self) -> u64 {
+        self.0 / 1_000
+    }
+
+    pub fn as_millis(self) -> u64 {
+        self.0
+    }
+}
+
+/// Configurable interval between successive miniblocks, accepted as fractional seconds and stored as
+/// milliseconds.
+#[derive(Debug, Clone, Copy)]
+pub struct MiniblockTimestampInterval {
+    millis: u64,
+}
+
+impl MiniblockTimestampInterval {
+    /// Builds the interval from a fractional-seconds config value (e.g. `0.25` for 250ms).
+    pub fn from_secs_f64(interval_secs: f64) -> Self {
+        assert!(
+            interval_secs > 0.0,
+            "miniblock timestamp interval must be positive"
+        );
+        Self {
+            millis: (interval_secs * 1_000.0).round() as u64,
+        }
+    }
+
+    pub fn as_millis(self) -> u64 {
+        self.millis
+    }
+
+    /// Returns the timestamp for the next miniblock given the previous one and a candidate sourced
+    /// from the timestamp provider, enforcing `next >= prev + interval` at millisecond granularity.
+    pub fn next_timestamp(
+        self,
+        prev: MillisTimestamp,
+        candidate: MillisTimestamp,
+    ) -> MillisTimestamp {
+        let floor = MillisTimestamp(prev.0 + self.millis);
+        candidate.max(floor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn as_secs_truncates() {
+        assert_eq!(MillisTimestamp(1_999).as_secs(), 1);
+        assert_eq!(MillisTimestamp(2_000).as_secs(), 2);
+    }
+
+    #[test]
+    fn interval_enforces_minimum_spacing() {
+        let interval = MiniblockTimestampInterval::from_secs_f64(0.25);
+        assert_eq!(interval.as_millis(), 250);
+        let prev = MillisTimestamp(1_000);
+        // Candidate behind the floor is bumped up to `prev + interval`.
+        assert_eq!(interval.next_timestamp(prev, MillisTimestamp(1_100)).0, 1_250);
+        // Candidate ahead of the floor is kept.
+        assert_eq!(interval.next_timestamp(prev, MillisTimestamp(1_400)).0, 1_400);
+    }
+}
@@ -0,0 +1,95 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::state_keeper::sync::{
+    atomic::{AtomicU64, Ordering},
+    Mutex,
+};
+
+/// Source of miniblock timestamps for the sealer.
+///
+/// Abstracting this out of the hard-coded wall-clock call makes deterministic testing possible
+/// (the monotonicity checks no longer race on a shared clock) and lets operators swap in alternate
+/// timestamp policies.
+///
+/// The sealer doesn't hold a `dyn TimestampProvider` yet — that field, and the call replacing its
+/// direct wall-clock read, belong to the seal loop, which isn't part of this module. Until then
+/// `WallClockTimestampProvider` describes current behavior without being wired in to reproduce it.
+pub trait TimestampProvider: Send + Sync + std::fmt::Debug {
+    /// Returns the timestamp for the next regular miniblock, given the previous miniblock's
+    /// timestamp.
+    fn next_miniblock_timestamp(&self, prev: u64) -> u64;
+
+    /// Returns the timestamp for the fictive miniblock that closes a batch. Defaults to reusing the
+    /// latest miniblock timestamp, which is the current behavior.
+    fn fictive_miniblock_timestamp(&self, latest_miniblock_timestamp: u64) -> u64 {
+        latest_miniblock_timestamp
+    }
+}
+
+/// Wall-clock provider: the current production behavior.
+#[derive(Debug, Default)]
+pub struct WallClockTimestampProvider;
+
+impl TimestampProvider for WallClockTimestampProvider {
+    fn next_miniblock_timestamp(&self, _prev: u64) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system time is before UNIX epoch")
+            .as_secs()
+    }
+}
+
+/// Deterministic provider that advances by a fixed step on every call. Used by tests so harnesses
+/// can assert exact timestamps without racing on a shared atomic clock.
+#[derive(Debug)]
+pub struct MonotonicTimestampProvider {
+    counter: AtomicU64,
+    step: u64,
+}
+
+impl MonotonicTimestampProvider {
+    pub fn new(start: u64, step: u64) -> Self {
+        assert!(step > 0, "step must be positive to stay monotonic");
+        Self {
+            counter: AtomicU64::new(start),
+            step,
+        }
+    }
+}
+
+impl Default for MonotonicTimestampProvider {
+    fn default() -> Self {
+        Self::new(0, 1)
+    }
+}
+
+impl TimestampProvider for MonotonicTimestampProvider {
+    fn next_miniblock_timestamp(&self, _prev: u64) -> u64 {
+        self.counter.fetch_add(self.step, Ordering::Relaxed) + self.step
+    }
+
+    fn fictive_miniblock_timestamp(&self, latest_miniblock_timestamp: u64) -> u64 {
+        // Keep the fictive miniblock strictly after the latest sealed one.
+        latest_miniblock_timestamp + self.step
+    }
+}
+
+/// Provider fed an explicit timestamp per batch by an external source (e.g. a consensus layer).
+#[derive(Debug, Default)]
+pub struct ExternallyFedTimestampProvider {
+    next: Mutex<Option<u64>>,
+}
+
+impl ExternallyFedTimestampProvider {
+    /// Supplies the timestamp to be used for the next miniblock.
+    pub fn feed(&self, timestamp: u64) {
+        *self.next.lock().unwrap() = Some(timestamp);
+    }
+}
+
+impl TimestampProvider for ExternallyFedTimestampProvider {
+    fn next_miniblock_timestamp(&self, prev: u64) -> u64 {
+        // Fall back to `prev + 1` if nothing was fed yet, so the sealer still makes progress.
+        self.next.lock().unwrap().take().unwrap_or(prev + 1)
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use zksync_types::H256;
+
+/// Tracks transactions that repeatedly force a batch rollback or get rejected and temporarily bans
+/// them so the IO layer stops feeding them back into block production.
+///
+/// A tx that deterministically re-triggers `bootloader_tip_out_of_gas` (or is repeatedly rejected)
+/// would otherwise waste a batch seal every round. Once a tx crosses `ban_threshold` offenses it is
+/// dropped into a ban set for `ban_window` units (block numbers or any monotonic clock supplied by
+/// the caller), after which it is automatically un-banned.
+///
+/// [`BoundedPendingSet::iter_ranked_unbanned`](crate::state_keeper::pending_set::BoundedPendingSet::iter_ranked_unbanned)
+/// is where a `TxBanManager` actually filters candidates; what this module doesn't provide is the
+/// other half — calling `record_offense` on a rollback and `prune_expired` periodically — since
+/// that lives in the seal loop, which isn't part of this module.
+#[derive(Debug)]
+pub struct TxBanManager {
+    ban_threshold: u32,
+    ban_window: u64,
+    offenses: HashMap<H256, u32>,
+    /// Maps a banned tx hash to the clock value at which the ban expires.
+    banned_until: HashMap<H256, u64>,
+}
+
+impl TxBanManager {
+    pub fn new(ban_threshold: u32, ban_window: u64) -> Self {
+        assert!(ban_threshold > 0, "ban threshold must be positive");
+        Self {
+            ban_threshold,
+            ban_window,
+            offenses: HashMap::new(),
+            banned_until: HashMap::new(),
+        }
+    }
+
+    /// Records that a tx caused a rollback or rejection at clock `now`, banning it once it crosses
+    /// the threshold. Returns `true` if the tx is banned as a result.
+    pub fn record_offense(&mut self, tx_hash: H256, now: u64) -> bool {
+        let count = self.offenses.entry(tx_hash).or_insert(0);
+        *count += 1;
+        if *count >= self.ban_threshold {
+            self.banned_until.insert(tx_hash, now + self.ban_window);
+            // Reset the counter so the tx gets a fresh chance after the ban elapses.
+            *count = 0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns whether a tx is currently banned, lazily dropping it from the ban set once its
+    /// window has elapsed.
+    pub fn is_banned(&mut self, tx_hash: &H256, now: u64) -> bool {
+        match self.banned_until.get(tx_hash) {
+            Some(&expires_at) if now < expires_at => true,
+            Some(_) => {
+                self.banned_until.remove(tx_hash);
+                false
+            }
+            None => false,
+        }
+    }
+
+    /// Removes all expired bans. Cheap to call periodically from the IO poll loop.
+    pub fn prune_expired(&mut self, now: u64) {
+        self.banned_until.retain(|_, &mut expires_at| now < expires_at);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bans_after_threshold_and_unbans_after_window() {
+        let mut manager = TxBanManager::new(2, 10);
+        let tx = H256::repeat_byte(0x42);
+
+        assert!(!manager.record_offense(tx, 0), "first offense should not ban");
+        assert!(manager.record_offense(tx, 5), "second offense should ban");
+        assert!(manager.is_banned(&tx, 5));
+        assert!(manager.is_banned(&tx, 14));
+        // Ban window (10) elapsed relative to the banning clock value (5).
+        assert!(!manager.is_banned(&tx, 15));
+    }
+}
@@ -0,0 +1,275 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
+
+use zksync_types::{Address, Nonce, Transaction, U256};
+
+/// Strategy used by the IO layer to order pending L2 transactions before offering them to the VM.
+///
+/// [`BoundedPendingSet`](crate::state_keeper::pending_set::BoundedPendingSet) accepts whichever
+/// mode it's given, but nothing in this crate yet selects `FeePriority` outside tests — the seal
+/// loop that would plumb an operator-configured mode through is not part of this module, so `Fifo`
+/// (the `Default`) is the only mode actually exercised in production today.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxOrderingMode {
+    /// Preserve the order in which transactions arrived.
+    Fifo,
+    /// Rank by effective fee per gas (highest first), with ties broken by per-account nonce to keep
+    /// each sender's transactions in sequence.
+    FeePriority,
+}
+
+impl Default for TxOrderingMode {
+    fn default() -> Self {
+        Self::Fifo
+    }
+}
+
+/// Effective fee per gas a transaction is willing to pay given the current `base_fee`:
+/// `min(max_fee_per_gas, base_fee + max_priority_fee_per_gas)`.
+pub(crate) fn effective_fee_per_gas(tx: &Transaction, base_fee: U256) -> U256 {
+    let max_fee = tx.max_fee_per_gas();
+    let priority_fee = tx.max_priority_fee_per_gas();
+    max_fee.min(base_fee + priority_fee)
+}
+
+/// Orders `txs` according to `mode`. FIFO leaves the input order untouched; fee-priority sorts by
+/// descending effective fee per gas of each sender's lowest-nonce transaction, emitting every
+/// sender's transactions in nonce order so they are never reordered relative to each other.
+pub fn order_transactions(
+    mut txs: Vec<Transaction>,
+    mode: TxOrderingMode,
+    base_fee: U256,
+) -> Vec<Transaction> {
+    match mode {
+        TxOrderingMode::Fifo => txs,
+        TxOrderingMode::FeePriority => {
+            // Preserve arrival order as the final tie-breaker so the sort is deterministic.
+            let arrival: HashMap<Address, usize> = {
+                let mut arrival = HashMap::new();
+                for (i, tx) in txs.iter().enumerate() {
+                    arrival.entry(tx.initiator_account()).or_insert(i);
+                }
+                arrival
+            };
+            // Rank accounts by the effective fee of their lowest-nonce transaction, then emit each
+            // sender's transactions in nonce order; this keeps per-account nonce sequencing intact
+            // while still prioritizing the highest-paying senders.
+            let lowest = lowest_nonces(&txs);
+            let mut by_account: HashMap<Address, Vec<Transaction>> = HashMap::new();
+            for tx in txs {
+                by_account
+                    .entry(tx.initiator_account())
+                    .or_default()
+                    .push(tx);
+            }
+
+            let mut accounts: Vec<Address> = by_account.keys().copied().collect();
+            accounts.sort_by_key(|account| {
+                let group = &by_account[account];
+                let head_fee = group
+                    .iter()
+                    .find(|tx| Some(tx.nonce()) == lowest.get(account).copied())
+                    .map(|tx| effective_fee_per_gas(tx, base_fee))
+                    .unwrap_or_default();
+                (
+                    Reverse(head_fee),
+                    arrival.get(account).copied().unwrap_or(usize::MAX),
+                )
+            });
+
+            let mut ordered = Vec::new();
+            for account in accounts {
+                let mut group = by_account.remove(&account).unwrap();
+                group.sort_by_key(|tx| tx.nonce());
+                ordered.extend(group);
+            }
+            ordered
+        }
+    }
+}
+
+/// Convenience helper used by the IO layer and tests: returns the highest-priority eligible
+/// transaction and the remaining ones in ranked order. The whole set is ordered via
+/// [`order_transactions`] to find the head; use [`ranked_iter`] to rank lazily.
+pub fn next_transaction(
+    txs: Vec<Transaction>,
+    mode: TxOrderingMode,
+    base_fee: U256,
+) -> Option<(Transaction, Vec<Transaction>)> {
+    let mut ordered = order_transactions(txs, mode, base_fee);
+    if ordered.is_empty() {
+        None
+    } else {
+        let head = ordered.remove(0);
+        Some((head, ordered))
+    }
+}
+
+/// Lazily yields `txs` best-first according to `mode`, ranking only as much of the input as the
+/// caller actually pulls.
+///
+/// [`TxOrderingMode::Fifo`] is arrival order already, so it costs nothing beyond the underlying
+/// `Vec` iterator. [`TxOrderingMode::FeePriority`] ranks incrementally via a binary heap over each
+/// sender's current head instead of sorting the whole window up front: advancing past a sender's
+/// head (finding that sender's next-lowest-nonce transaction) only happens once that head is
+/// actually yielded. A caller that stops early — e.g. because a seal was reached — never pays the
+/// cost of ranking the senders it never got to.
+pub fn ranked_iter(txs: Vec<Transaction>, mode: TxOrderingMode, base_fee: U256) -> RankedIter {
+    match mode {
+        TxOrderingMode::Fifo => RankedIter::Fifo(txs.into_iter()),
+        TxOrderingMode::FeePriority => RankedIter::FeePriority(FeePriorityIter::new(txs, base_fee)),
+    }
+}
+
+/// Iterator returned by [`ranked_iter`]. See that function for the laziness guarantee.
+pub enum RankedIter {
+    Fifo(std::vec::IntoIter<Transaction>),
+    FeePriority(FeePriorityIter),
+}
+
+impl RankedIter {
+    /// Consumes the iterator and returns whatever it hadn't yielded yet, in unspecified order,
+    /// without paying for any further ranking. Used by a caller that stops consuming early and
+    /// wants the rest back untouched, to be re-ranked from scratch next time (e.g.
+    /// [`crate::state_keeper::pending_set::BoundedPendingSet::drain_until_seal`] once sealed).
+    pub fn into_unranked_remainder(self) -> Vec<Transaction> {
+        match self {
+            RankedIter::Fifo(iter) => iter.collect(),
+            RankedIter::FeePriority(iter) => iter.into_unranked_remainder(),
+        }
+    }
+}
+
+impl Iterator for RankedIter {
+    type Item = Transaction;
+
+    fn next(&mut self) -> Option<Transaction> {
+        match self {
+            RankedIter::Fifo(iter) => iter.next(),
+            RankedIter::FeePriority(iter) => iter.next(),
+        }
+    }
+}
+
+/// Ranks each account's current head by effective fee (ties broken by arrival order), without
+/// ever sorting the full transaction set: a sender's remaining transactions are plain, unsorted
+/// storage until that sender's head is consumed, at which point the next-lowest-nonce transaction
+/// is located (and re-ranked) on demand.
+pub struct FeePriorityIter {
+    base_fee: U256,
+    by_account: HashMap<Address, Vec<Transaction>>,
+    heap: BinaryHeap<HeapEntry>,
+}
+
+struct HeapEntry {
+    fee: U256,
+    arrival: usize,
+    account: Address,
+}
+
+impl PartialEq for HeapEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.fee == other.fee && self.arrival == other.arrival
+    }
+}
+
+impl Eq for HeapEntry {}
+
+impl PartialOrd for HeapEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HeapEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `BinaryHeap` is a max-heap: rank by fee, and on a tie prefer the earlier arrival (lower
+        // `arrival` index) to come out "greater" so it is popped first.
+        self.fee
+            .cmp(&other.fee)
+            .then_with(|| other.arrival.cmp(&self.arrival))
+    }
+}
+
+impl FeePriorityIter {
+    fn new(txs: Vec<Transaction>, base_fee: U256) -> Self {
+        let mut arrival: HashMap<Address, usize> = HashMap::new();
+        let mut by_account: HashMap<Address, Vec<Transaction>> = HashMap::new();
+        for (i, tx) in txs.into_iter().enumerate() {
+            let account = tx.initiator_account();
+            arrival.entry(account).or_insert(i);
+            by_account.entry(account).or_default().push(tx);
+        }
+
+        let mut heap = BinaryHeap::with_capacity(by_account.len());
+        for (&account, group) in &by_account {
+            let head_fee = Self::head_fee(group, base_fee);
+            heap.push(HeapEntry {
+                fee: head_fee,
+                arrival: arrival[&account],
+                account,
+            });
+        }
+
+        Self {
+            base_fee,
+            by_account,
+            heap,
+        }
+    }
+
+    /// Effective fee of the lowest-nonce (i.e. head) transaction in `group`.
+    fn head_fee(group: &[Transaction], base_fee: U256) -> U256 {
+        group
+            .iter()
+            .min_by_key(|tx| tx.nonce())
+            .map(|tx| effective_fee_per_gas(tx, base_fee))
+            .unwrap_or_default()
+    }
+
+    /// Consumes the iterator, returning whatever hasn't been yielded yet without ranking it.
+    fn into_unranked_remainder(self) -> Vec<Transaction> {
+        self.by_account.into_values().flatten().collect()
+    }
+}
+
+impl Iterator for FeePriorityIter {
+    type Item = Transaction;
+
+    fn next(&mut self) -> Option<Transaction> {
+        let entry = self.heap.pop()?;
+        let group = self.by_account.get_mut(&entry.account)?;
+        let head_index = group
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, tx)| tx.nonce())
+            .map(|(index, _)| index)?;
+        let head = group.remove(head_index);
+
+        if group.is_empty() {
+            self.by_account.remove(&entry.account);
+        } else {
+            let head_fee = Self::head_fee(group, self.base_fee);
+            self.heap.push(HeapEntry {
+                fee: head_fee,
+                arrival: entry.arrival,
+                account: entry.account,
+            });
+        }
+
+        Some(head)
+    }
+}
+
+/// Returns the lowest pending nonce per account, used to keep per-sender sequencing intact when
+/// selecting across accounts under fee-priority ordering.
+pub fn lowest_nonces(txs: &[Transaction]) -> HashMap<Address, Nonce> {
+    let mut lowest: HashMap<Address, Nonce> = HashMap::new();
+    for tx in txs {
+        let entry = lowest.entry(tx.initiator_account()).or_insert(tx.nonce());
+        if tx.nonce() < *entry {
+            *entry = tx.nonce();
+        }
+    }
+    lowest
+}